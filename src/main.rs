@@ -1,17 +1,37 @@
 use std::{
     fs::File,
-    io::{stdin, stdout, BufReader, Read, Write},
+    io::{self, stdin, stdout, BufReader, BufWriter, Read, Write},
 };
 
 use base64::{
-    alphabet::URL_SAFE,
-    engine::general_purpose::{GeneralPurpose, NO_PAD},
+    alphabet::{Alphabet, STANDARD, URL_SAFE},
+    engine::{
+        general_purpose::{NO_PAD, PAD},
+        DecodePaddingMode, GeneralPurpose,
+    },
     Engine,
 };
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 
 const STDIN: &str = "-";
-const URL_SAFE_NO_PAD_ENGINE: GeneralPurpose = GeneralPurpose::new(&URL_SAFE, NO_PAD);
+
+// Symbol sets for `--ignore-garbage` to filter non-alphabet bytes against.
+const STANDARD_SYMBOLS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE_SYMBOLS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+// Must be a multiple of 3 so every full block maps to whole base64 groups.
+const ENCODE_BLOCK_SIZE: usize = 3 * 1024;
+// Must be a multiple of 4 so every full block holds whole base64 groups.
+const DECODE_BLOCK_SIZE: usize = 4 * 1024;
+
+/// Base64 alphabet to encode/decode with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Variant {
+    /// RFC 4648 URL- and filename-safe alphabet (`-` and `_`).
+    UrlSafe,
+    /// RFC 4648 standard alphabet (`+` and `/`).
+    Standard,
+}
 
 #[derive(Debug, Parser)]
 #[clap(about, version)]
@@ -20,55 +40,419 @@ struct Args {
     #[clap(long, short)]
     decode: bool,
 
-    /// With no FILE, or when FILE is -, read standard input.
+    /// Base64 alphabet to use.
+    #[clap(long, value_enum, default_value_t = Variant::UrlSafe)]
+    variant: Variant,
+
+    /// Pad encoded output with `=`.
+    #[clap(long)]
+    pad: bool,
+
+    /// Wrap encoded lines after COLS characters. 0 disables wrapping.
+    #[clap(long, default_value_t = 76)]
+    wrap: usize,
+
+    /// When decoding, ignore non-alphabet characters instead of erroring.
+    #[clap(long, short = 'i')]
+    ignore_garbage: bool,
+
+    /// Write output to PATH instead of standard output.
+    #[clap(long, short)]
+    output: Option<String>,
+
+    /// With no FILE, or when FILE is -, read standard input. Multiple FILEs
+    /// are concatenated in order before encoding/decoding.
     #[clap(value_parser)]
-    file: Option<String>,
+    file: Vec<String>,
 }
 
-fn encode(mut input: impl Read, mut output: impl Write) -> Result<(), anyhow::Error> {
-    let decoded = {
-        let mut buf = Vec::new();
-        input.read_to_end(&mut buf)?;
-        buf
-    };
-    let encoded = URL_SAFE_NO_PAD_ENGINE.encode(decoded.as_slice());
+impl Args {
+    /// The alphabet selected by `--variant`.
+    fn alphabet(&self) -> Alphabet {
+        match self.variant {
+            Variant::UrlSafe => URL_SAFE,
+            Variant::Standard => STANDARD,
+        }
+    }
+
+    /// The symbols of the alphabet selected by `--variant`.
+    fn alphabet_symbols(&self) -> &'static str {
+        match self.variant {
+            Variant::UrlSafe => URL_SAFE_SYMBOLS,
+            Variant::Standard => STANDARD_SYMBOLS,
+        }
+    }
 
-    writeln!(output, "{encoded}")?;
+    /// Builds the engine selected by `--variant`/`--pad`. Decoding always
+    /// accepts input with or without padding.
+    fn engine(&self) -> GeneralPurpose {
+        let config = if self.pad { PAD } else { NO_PAD }
+            .with_decode_padding_mode(DecodePaddingMode::Indifferent);
 
-    Ok(())
+        GeneralPurpose::new(&self.alphabet(), config)
+    }
 }
 
-fn decode(mut input: impl Read, mut output: impl Write) -> Result<(), anyhow::Error> {
-    let mut buf = String::new();
-    input.read_to_string(&mut buf)?;
-    let encoded = buf.trim_end();
-    let decoded = URL_SAFE_NO_PAD_ENGINE.decode(encoded)?;
+/// Fills `buf` from `input`, looping over short reads. Returns the number
+/// of bytes filled.
+fn fill_buf(mut input: impl Read, buf: &mut [u8]) -> Result<usize, anyhow::Error> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match input.read(&mut buf[filled..])? {
+            0 => break,
+            n => filled += n,
+        }
+    }
 
-    output.write_all(&decoded)?;
+    Ok(filled)
+}
 
-    Ok(())
+/// Inserts a newline every `wrap` characters. `wrap == 0` disables wrapping.
+struct WrapWriter<W: Write> {
+    inner: W,
+    wrap: usize,
+    column: usize,
+    any_written: bool,
 }
 
-fn execute(stdin: impl Read, stdout: impl Write, args: &Args) -> Result<(), anyhow::Error> {
-    match args.file.as_deref() {
-        // From standard input
-        Some(STDIN) | None => {
-            if args.decode {
-                decode(stdin, stdout)?;
-            } else {
-                encode(stdin, stdout)?;
+impl<W: Write> WrapWriter<W> {
+    fn new(inner: W, wrap: usize) -> Self {
+        Self {
+            inner,
+            wrap,
+            column: 0,
+            any_written: false,
+        }
+    }
+
+    /// Writes the trailing newline, matching the unwrapped writer's
+    /// historical always-newline behavior.
+    fn finish(mut self) -> Result<(), anyhow::Error> {
+        if self.wrap == 0 || self.column > 0 || !self.any_written {
+            self.inner.write_all(b"\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for WrapWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if !buf.is_empty() {
+            self.any_written = true;
+        }
+
+        if self.wrap == 0 {
+            return self.inner.write(buf);
+        }
+
+        let mut remaining = buf;
+        while !remaining.is_empty() {
+            let take = (self.wrap - self.column).min(remaining.len());
+            self.inner.write_all(&remaining[..take])?;
+            self.column += take;
+            remaining = &remaining[take..];
+
+            if self.column == self.wrap {
+                self.inner.write_all(b"\n")?;
+                self.column = 0;
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reads from `inner`, discarding `\r` and `\n` unconditionally, the way
+/// coreutils' `base64 -d` always ignores line breaks.
+struct StripLineBreaksReader<R: Read> {
+    inner: R,
+}
+
+impl<R: Read> StripLineBreaksReader<R> {
+    fn new(inner: R) -> Self {
+        Self { inner }
+    }
+}
+
+impl<R: Read> Read for StripLineBreaksReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut scratch = vec![0; buf.len()];
+        loop {
+            let n = self.inner.read(&mut scratch)?;
+            if n == 0 {
+                return Ok(0);
+            }
+
+            let mut written = 0;
+            for &byte in &scratch[..n] {
+                if byte != b'\n' && byte != b'\r' {
+                    buf[written] = byte;
+                    written += 1;
+                }
+            }
+
+            if written > 0 {
+                return Ok(written);
             }
         }
-        // From FILE
-        Some(p) => {
-            let file = BufReader::new(File::open(p)?);
-            if args.decode {
-                decode(file, stdout)?;
-            } else {
-                encode(file, stdout)?;
+    }
+}
+
+/// Reads from `inner`, remapping whichever of `+`/`/` (standard) or `-`/`_`
+/// (URL-safe) `variant` doesn't use to the symbol it does, so decode
+/// accepts either alphabet's data regardless of `--variant`.
+struct NormalizeAlphabetReader<R: Read> {
+    inner: R,
+    variant: Variant,
+}
+
+impl<R: Read> NormalizeAlphabetReader<R> {
+    fn new(inner: R, variant: Variant) -> Self {
+        Self { inner, variant }
+    }
+}
+
+impl<R: Read> Read for NormalizeAlphabetReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        for byte in &mut buf[..n] {
+            *byte = match (self.variant, *byte) {
+                (Variant::UrlSafe, b'+') => b'-',
+                (Variant::UrlSafe, b'/') => b'_',
+                (Variant::Standard, b'-') => b'+',
+                (Variant::Standard, b'_') => b'/',
+                (_, byte) => byte,
+            };
+        }
+
+        Ok(n)
+    }
+}
+
+/// Reads from `inner`, discarding any byte outside `alphabet` or `=`.
+struct IgnoreGarbageReader<R: Read> {
+    inner: R,
+    alphabet: &'static str,
+}
+
+impl<R: Read> IgnoreGarbageReader<R> {
+    fn new(inner: R, alphabet: &'static str) -> Self {
+        Self { inner, alphabet }
+    }
+
+    fn is_garbage(&self, byte: u8) -> bool {
+        byte != b'=' && !self.alphabet.as_bytes().contains(&byte)
+    }
+}
+
+impl<R: Read> Read for IgnoreGarbageReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut scratch = vec![0; buf.len()];
+        loop {
+            let n = self.inner.read(&mut scratch)?;
+            if n == 0 {
+                return Ok(0);
             }
+
+            let mut written = 0;
+            for &byte in &scratch[..n] {
+                if !self.is_garbage(byte) {
+                    buf[written] = byte;
+                    written += 1;
+                }
+            }
+
+            if written > 0 {
+                return Ok(written);
+            }
+        }
+    }
+}
+
+/// Reads from `inner`, dropping trailing ASCII whitespace (as defined by
+/// `u8::is_ascii_whitespace`), even when it spans multiple reads.
+struct TrimTrailingWhitespaceReader<R: Read> {
+    inner: R,
+    pending_whitespace: Vec<u8>,
+    confirmed: std::collections::VecDeque<u8>,
+    eof: bool,
+}
+
+impl<R: Read> TrimTrailingWhitespaceReader<R> {
+    fn new(inner: R) -> Self {
+        Self {
+            inner,
+            pending_whitespace: Vec::new(),
+            confirmed: std::collections::VecDeque::new(),
+            eof: false,
         }
     }
+}
+
+impl<R: Read> Read for TrimTrailingWhitespaceReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut scratch = vec![0; buf.len().max(1)];
+        while self.confirmed.is_empty() && !self.eof {
+            let n = self.inner.read(&mut scratch)?;
+            if n == 0 {
+                self.eof = true;
+                self.pending_whitespace.clear();
+                break;
+            }
+
+            for &byte in &scratch[..n] {
+                if byte.is_ascii_whitespace() {
+                    self.pending_whitespace.push(byte);
+                } else {
+                    self.confirmed.extend(self.pending_whitespace.drain(..));
+                    self.confirmed.push_back(byte);
+                }
+            }
+        }
+
+        let mut written = 0;
+        while written < buf.len() {
+            match self.confirmed.pop_front() {
+                Some(byte) => {
+                    buf[written] = byte;
+                    written += 1;
+                }
+                None => break,
+            }
+        }
+
+        Ok(written)
+    }
+}
+
+fn encode(
+    mut input: impl Read,
+    output: impl Write,
+    engine: &GeneralPurpose,
+    wrap: usize,
+) -> Result<(), anyhow::Error> {
+    let mut output = WrapWriter::new(output, wrap);
+    let mut buf = [0; ENCODE_BLOCK_SIZE];
+    loop {
+        let len = fill_buf(&mut input, &mut buf)?;
+        if len == 0 {
+            break;
+        }
+
+        let encoded = engine.encode(&buf[..len]);
+        output.write_all(encoded.as_bytes())?;
+
+        if len < buf.len() {
+            break;
+        }
+    }
+    output.finish()?;
+
+    Ok(())
+}
+
+fn decode(
+    input: impl Read,
+    output: impl Write,
+    engine: &GeneralPurpose,
+    variant: Variant,
+    alphabet: &'static str,
+    ignore_garbage: bool,
+) -> Result<(), anyhow::Error> {
+    let input = StripLineBreaksReader::new(input);
+    let input = NormalizeAlphabetReader::new(input, variant);
+    let input = TrimTrailingWhitespaceReader::new(input);
+    if ignore_garbage {
+        decode_from(IgnoreGarbageReader::new(input, alphabet), output, engine)
+    } else {
+        decode_from(input, output, engine)
+    }
+}
+
+fn decode_from(
+    mut input: impl Read,
+    mut output: impl Write,
+    engine: &GeneralPurpose,
+) -> Result<(), anyhow::Error> {
+    let mut buf = [0; DECODE_BLOCK_SIZE];
+    loop {
+        let len = fill_buf(&mut input, &mut buf)?;
+        if len == 0 {
+            break;
+        }
+
+        let decoded = engine.decode(&buf[..len])?;
+        output.write_all(&decoded)?;
+
+        if len < buf.len() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Opens `files` in order and chains them into a single stream; an empty
+/// `files` list (or a literal `-`) reads `stdin` instead.
+fn build_input<'a>(
+    stdin: impl Read + 'a,
+    files: &[String],
+) -> Result<Box<dyn Read + 'a>, anyhow::Error> {
+    let names: Vec<&str> = if files.is_empty() {
+        vec![STDIN]
+    } else {
+        files.iter().map(String::as_str).collect()
+    };
+
+    let mut stdin = Some(stdin);
+    let mut readers: Vec<Box<dyn Read + 'a>> = Vec::with_capacity(names.len());
+    for name in names {
+        let reader: Box<dyn Read + 'a> = if name == STDIN {
+            match stdin.take() {
+                Some(stdin) => Box::new(stdin),
+                None => Box::new(io::empty()),
+            }
+        } else {
+            Box::new(BufReader::new(File::open(name)?))
+        };
+        readers.push(reader);
+    }
+
+    Ok(readers
+        .into_iter()
+        .reduce(|a, b| Box::new(a.chain(b)) as Box<dyn Read + 'a>)
+        .unwrap_or_else(|| Box::new(io::empty())))
+}
+
+/// Routes output to `path` when given, or to `stdout` otherwise.
+fn build_output<'a>(
+    stdout: impl Write + 'a,
+    output: Option<&str>,
+) -> Result<Box<dyn Write + 'a>, anyhow::Error> {
+    match output {
+        Some(path) => Ok(Box::new(BufWriter::new(File::create(path)?))),
+        None => Ok(Box::new(stdout)),
+    }
+}
+
+fn execute(stdin: impl Read, stdout: impl Write, args: &Args) -> Result<(), anyhow::Error> {
+    let engine = args.engine();
+    let alphabet = args.alphabet_symbols();
+
+    let input = build_input(stdin, &args.file)?;
+    let mut output = build_output(stdout, args.output.as_deref())?;
+
+    if args.decode {
+        decode(input, &mut output, &engine, args.variant, alphabet, args.ignore_garbage)?;
+    } else {
+        encode(input, &mut output, &engine, args.wrap)?;
+    }
+    output.flush()?;
 
     Ok(())
 }
@@ -110,19 +494,91 @@ mod tests {
 
     const TRAILING_WHITESPACES: [&[u8]; 7] = [b"", b" ", b"  ", b"   ", b"\n", b"\n\n", b"\n\n\n"];
 
+    fn url_safe_no_pad_engine() -> GeneralPurpose {
+        Args {
+            decode: false,
+            variant: Variant::UrlSafe,
+            pad: false,
+            wrap: 76,
+            ignore_garbage: false,
+            output: None,
+            file: vec![],
+        }
+        .engine()
+    }
+
+    #[cfg(test)]
+    mod engine {
+        use super::*;
+
+        #[test]
+        fn it_selects_the_alphabet_from_variant() {
+            let args = Args {
+                decode: false,
+                variant: Variant::Standard,
+                pad: false,
+                wrap: 76,
+                ignore_garbage: false,
+                output: None,
+                file: vec![],
+            };
+            assert_eq!(args.engine().encode(b"\xFB\xFF\xBF"), "+/+/");
+        }
+
+        #[test]
+        fn it_pads_encoded_output_when_pad_is_set() {
+            let args = Args {
+                decode: false,
+                variant: Variant::UrlSafe,
+                pad: true,
+                wrap: 76,
+                ignore_garbage: false,
+                output: None,
+                file: vec![],
+            };
+            assert_eq!(args.engine().encode(b"hello"), "aGVsbG8=");
+        }
+
+        #[test]
+        fn it_decodes_padded_and_unpadded_input_regardless_of_pad() {
+            let args = Args {
+                decode: true,
+                variant: Variant::UrlSafe,
+                pad: false,
+                wrap: 76,
+                ignore_garbage: false,
+                output: None,
+                file: vec![],
+            };
+            let engine = args.engine();
+            assert_eq!(engine.decode("aGVsbG8").unwrap(), b"hello");
+            assert_eq!(engine.decode("aGVsbG8=").unwrap(), b"hello");
+        }
+    }
+
     #[cfg(test)]
     mod encode {
         use super::*;
 
         #[test]
         fn it_writes_encoded_bytes() {
+            let engine = url_safe_no_pad_engine();
             for (raw, encoded) in ENCODE_TEST_CASES {
                 let mut input = Cursor::new(raw);
                 let mut output = Vec::new();
-                assert!(encode(&mut input, &mut output).is_ok());
+                assert!(encode(&mut input, &mut output, &engine, 0).is_ok());
                 assert_eq!(output, [encoded, b"\n"].concat());
             }
         }
+
+        #[test]
+        fn it_wraps_output_every_wrap_characters() {
+            let engine = url_safe_no_pad_engine();
+            let mut input = Cursor::new(b"\xde\x9a\x4c\x32\x9e\x0d\x5b\xa8\x39\xed\x33\x5b\xe1\x9c\x01\xd9");
+            let mut output = Vec::new();
+            assert!(encode(&mut input, &mut output, &engine, 8).is_ok());
+            assert_eq!(output, b"3ppMMp4N\nW6g57TNb\n4ZwB2Q\n".to_vec());
+        }
     }
 
     #[cfg(test)]
@@ -131,25 +587,77 @@ mod tests {
 
         #[test]
         fn it_writes_decoded_bytes() {
+            let engine = url_safe_no_pad_engine();
             for (raw, encoded) in ENCODE_TEST_CASES {
                 let mut input = Cursor::new(encoded);
                 let mut output = Vec::new();
-                assert!(decode(&mut input, &mut output).is_ok());
+                let variant = Variant::UrlSafe;
+                assert!(
+                    decode(&mut input, &mut output, &engine, variant, URL_SAFE_SYMBOLS, false)
+                        .is_ok()
+                );
                 assert_eq!(output, raw);
             }
         }
 
         #[test]
         fn it_ignores_trailing_whitespace() {
+            let engine = url_safe_no_pad_engine();
             for trailing_whitespace in TRAILING_WHITESPACES {
                 for (raw, encoded) in ENCODE_TEST_CASES {
                     let mut input = Cursor::new([encoded, trailing_whitespace].concat());
                     let mut output = Vec::new();
-                    assert!(decode(&mut input, &mut output).is_ok());
+                    let variant = Variant::UrlSafe;
+                    assert!(
+                        decode(&mut input, &mut output, &engine, variant, URL_SAFE_SYMBOLS, false)
+                            .is_ok()
+                    );
                     assert_eq!(output, raw);
                 }
             }
         }
+
+        #[test]
+        fn it_ignores_garbage_when_ignore_garbage_is_set() {
+            let engine = url_safe_no_pad_engine();
+            let mut input = Cursor::new(b"aG!Vs\nbG8 ".to_vec());
+            let mut output = Vec::new();
+            assert!(
+                decode(&mut input, &mut output, &engine, Variant::UrlSafe, URL_SAFE_SYMBOLS, true)
+                    .is_ok()
+            );
+            assert_eq!(output, b"hello");
+        }
+
+        #[test]
+        fn it_decodes_output_wrapped_across_multiple_lines() {
+            let engine = url_safe_no_pad_engine();
+            let raw = [0u8; 60];
+
+            let mut encoded = Vec::new();
+            assert!(encode(&mut Cursor::new(raw), &mut encoded, &engine, 76).is_ok());
+            assert!(encoded.iter().filter(|&&b| b == b'\n').count() > 1);
+
+            let mut input = Cursor::new(encoded);
+            let mut output = Vec::new();
+            let variant = Variant::UrlSafe;
+            assert!(
+                decode(&mut input, &mut output, &engine, variant, URL_SAFE_SYMBOLS, false).is_ok()
+            );
+            assert_eq!(output, raw);
+        }
+
+        #[test]
+        fn it_decodes_standard_alphabet_data_under_the_url_safe_variant() {
+            let engine = url_safe_no_pad_engine();
+            let mut input = Cursor::new(b"+/+/".to_vec());
+            let mut output = Vec::new();
+            assert!(
+                decode(&mut input, &mut output, &engine, Variant::UrlSafe, URL_SAFE_SYMBOLS, false)
+                    .is_ok()
+            );
+            assert_eq!(output, b"\xFB\xFF\xBF");
+        }
     }
 
     #[cfg(test)]
@@ -161,11 +669,21 @@ mod tests {
             let argss = [
                 Args {
                     decode: false,
-                    file: Some("-".to_string()),
+                    variant: Variant::UrlSafe,
+                    pad: false,
+                    wrap: 76,
+                    ignore_garbage: false,
+                    output: None,
+                    file: vec!["-".to_string()],
                 },
                 Args {
                     decode: false,
-                    file: None,
+                    variant: Variant::UrlSafe,
+                    pad: false,
+                    wrap: 76,
+                    ignore_garbage: false,
+                    output: None,
+                    file: vec![],
                 },
             ];
             for args in argss {
@@ -191,7 +709,12 @@ mod tests {
                 let args = {
                     Args {
                         decode: false,
-                        file: Some(tempfile.path().display().to_string()),
+                        variant: Variant::UrlSafe,
+                        pad: false,
+                        wrap: 76,
+                        ignore_garbage: false,
+                        output: None,
+                        file: vec![tempfile.path().display().to_string()],
                     }
                 };
                 assert!(execute(&mut stdin, &mut stdout, &args).is_ok());
@@ -204,11 +727,21 @@ mod tests {
             let argss = [
                 Args {
                     decode: true,
-                    file: Some("-".to_string()),
+                    variant: Variant::UrlSafe,
+                    pad: false,
+                    wrap: 76,
+                    ignore_garbage: false,
+                    output: None,
+                    file: vec!["-".to_string()],
                 },
                 Args {
                     decode: true,
-                    file: None,
+                    variant: Variant::UrlSafe,
+                    pad: false,
+                    wrap: 76,
+                    ignore_garbage: false,
+                    output: None,
+                    file: vec![],
                 },
             ];
             for args in argss {
@@ -234,12 +767,66 @@ mod tests {
                 let args = {
                     Args {
                         decode: true,
-                        file: Some(tempfile.path().display().to_string()),
+                        variant: Variant::UrlSafe,
+                        pad: false,
+                        wrap: 76,
+                        ignore_garbage: false,
+                        output: None,
+                        file: vec![tempfile.path().display().to_string()],
                     }
                 };
                 assert!(execute(&mut stdin, &mut stdout, &args).is_ok());
                 assert_eq!(stdout, raw);
             }
         }
+
+        #[test]
+        fn it_encodes_multiple_files_concatenated() {
+            let mut stdin = Cursor::new(Vec::new());
+            let mut stdout = Vec::new();
+            let first = {
+                let mut f = NamedTempFile::new().unwrap();
+                f.write_all(b"John").unwrap();
+                f
+            };
+            let second = {
+                let mut f = NamedTempFile::new().unwrap();
+                f.write_all(b" Doe").unwrap();
+                f
+            };
+            let args = Args {
+                decode: false,
+                variant: Variant::UrlSafe,
+                pad: false,
+                wrap: 76,
+                ignore_garbage: false,
+                output: None,
+                file: vec![
+                    first.path().display().to_string(),
+                    second.path().display().to_string(),
+                ],
+            };
+            assert!(execute(&mut stdin, &mut stdout, &args).is_ok());
+            assert_eq!(stdout, b"Sm9obiBEb2U\n");
+        }
+
+        #[test]
+        fn it_writes_to_the_output_file() {
+            let mut stdin = Cursor::new(b"hello".to_vec());
+            let mut stdout = Vec::new();
+            let output_file = NamedTempFile::new().unwrap();
+            let args = Args {
+                decode: false,
+                variant: Variant::UrlSafe,
+                pad: false,
+                wrap: 76,
+                ignore_garbage: false,
+                output: Some(output_file.path().display().to_string()),
+                file: vec![],
+            };
+            assert!(execute(&mut stdin, &mut stdout, &args).is_ok());
+            assert!(stdout.is_empty());
+            assert_eq!(std::fs::read(output_file.path()).unwrap(), b"aGVsbG8\n");
+        }
     }
 }